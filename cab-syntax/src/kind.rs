@@ -43,10 +43,13 @@ pub enum Kind {
     #[display("a comment")]
     TOKEN_COMMENT, // #[^\r\n]* and (#{3,}).*\1
 
-    #[display("'<|'")]
-    TOKEN_LESS_PIPE,
-    #[display("'|>'")]
-    TOKEN_PIPE_MORE,
+    // Punctuation kinds are fully decomposed: every operator the lexer
+    // produces is a single character, and composites such as `==>` or `<==`
+    // are reassembled by the parser from runs of tokens whose lexer-reported
+    // [`Spacing`](crate::Spacing) is `Joint`. This keeps maximal-munch out of
+    // the lexer and lets the token stream round-trip exactly.
+    #[display("'|'")]
+    TOKEN_PIPE,
 
     #[display("'@'")]
     TOKEN_AT,
@@ -56,19 +59,11 @@ pub enum Kind {
     #[display("')'")]
     TOKEN_RIGHT_PARENTHESIS,
 
-    #[display("'++'")]
-    TOKEN_PLUS_PLUS,
     #[display("'['")]
     TOKEN_LEFT_BRACKET,
     #[display("']'")]
     TOKEN_RIGHT_BRACKET,
 
-    #[display("'==>'")]
-    TOKEN_EQUAL_EQUAL_MORE,
-    #[display("'<=='")]
-    TOKEN_LESS_EQUAL_EQUAL,
-    #[display("'//'")]
-    TOKEN_SLASH_SLASH,
     #[display("'.'")]
     TOKEN_PERIOD,
     #[display("'{{'")]
@@ -80,22 +75,14 @@ pub enum Kind {
     #[display("';'")]
     TOKEN_SEMICOLON,
 
-    #[display("'!='")]
-    TOKEN_EXCLAMATION_EQUAL,
-    #[display("'=='")]
-    TOKEN_EQUAL_EQUAL,
+    #[display("'!'")]
+    TOKEN_EXCLAMATION,
     #[display("'='")]
     TOKEN_EQUAL,
-    #[display("'<='")]
-    TOKEN_LESS_EQUAL,
     #[display("'<'")]
     TOKEN_LESS,
-    #[display("'>='")]
-    TOKEN_MORE_EQUAL,
     #[display("'>'")]
     TOKEN_MORE,
-    #[display("'->'")]
-    TOKEN_MINUS_MORE,
 
     #[display("','")]
     TOKEN_COMMA,
@@ -106,8 +93,6 @@ pub enum Kind {
     TOKEN_PLUS,
     #[display("'-'")]
     TOKEN_MINUS,
-    #[display("'**'")]
-    TOKEN_ASTERISK_ASTERISK,
     #[display("'*'")]
     TOKEN_ASTERISK,
     #[display("'/'")]
@@ -0,0 +1,347 @@
+use cab_text::{
+    Applicability,
+    Span,
+    Suggestion,
+};
+
+use crate::{
+    unicode_confusable,
+    Kind,
+    Spacing,
+};
+
+/// A token as produced by [`scan`], before trivia is stripped and `Spacing`
+/// is computed.
+struct RawToken {
+    kind: Kind,
+    start: u32,
+    end: u32,
+}
+
+/// Scans `source` into the token stream the parser consumes: significant
+/// tokens paired with the [`Spacing`] of whatever immediately follows them
+/// in the byte stream.
+///
+/// Punctuation [`Kind`]s are single characters; composite operators like
+/// `==>` are left for the parser to reassemble from runs of
+/// [`Spacing::Joint`] tokens. `Joint` is computed purely from byte
+/// adjacency, so it round-trips: trivia
+/// ([`TOKEN_COMMENT`](Kind::TOKEN_COMMENT),
+/// [`TOKEN_WHITESPACE`](Kind::TOKEN_WHITESPACE)) between two tokens always
+/// forces [`Spacing::Alone`], even though it is dropped from the returned
+/// stream.
+///
+/// `\(` ([`TOKEN_INTERPOLATION_START`](Kind::TOKEN_INTERPOLATION_START)) is
+/// lexed atomically rather than as `\` followed by `(`, since it opens a
+/// nested expression instead of being punctuation to reassemble.
+///
+/// A comment always ends at the next newline; the multiline form opened by
+/// several `#` characters is not recognized.
+///
+/// Identifiers, keywords, integers and floats are also recognized.
+/// Stringlikes (`TOKEN_STRING_START`/`END`, `TOKEN_PATH`, `TOKEN_ISLAND_START`/`END`)
+/// are not: they nest interpolated expressions inside themselves, which
+/// needs a stateful, recursive lexer this flat scanner does not attempt.
+pub fn tokenize(source: &str) -> Vec<(Kind, Spacing)> {
+    tokenize_with_suggestions(source).0
+}
+
+/// Like [`tokenize`], but also returns the [`ErrorRecovery`]s collected
+/// while recovering [`Kind::TOKEN_ERROR`] characters that turned out to be
+/// [`unicode_confusable`]s.
+pub fn tokenize_with_suggestions(source: &str) -> (Vec<(Kind, Spacing)>, Vec<ErrorRecovery>) {
+    let (raw, recoveries) = scan(source);
+
+    let tokens = raw
+        .iter()
+        .enumerate()
+        .filter(|(_, token)| !token.kind.is_trivia())
+        .map(|(index, token)| {
+            let joint = raw
+                .get(index + 1)
+                .is_some_and(|next| next.start == token.end && !next.kind.is_trivia());
+
+            (token.kind, if joint { Spacing::Joint } else { Spacing::Alone })
+        })
+        .collect();
+
+    (tokens, recoveries)
+}
+
+fn scan(source: &str) -> (Vec<RawToken>, Vec<ErrorRecovery>) {
+    let chars: Vec<(usize, char)> = source.char_indices().collect();
+
+    let mut tokens = Vec::new();
+    let mut recoveries = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (start, ch) = chars[i];
+
+        let kind = if ch.is_whitespace() {
+            i += 1;
+            while i < chars.len() && chars[i].1.is_whitespace() {
+                i += 1;
+            }
+            Kind::TOKEN_WHITESPACE
+        } else if ch == '#' {
+            i += 1;
+            while i < chars.len() && chars[i].1 != '\n' {
+                i += 1;
+            }
+            Kind::TOKEN_COMMENT
+        } else if ch == '\\' && chars.get(i + 1).is_some_and(|&(_, next)| next == '(') {
+            i += 2;
+            Kind::TOKEN_INTERPOLATION_START
+        } else if ch.is_ascii_digit() {
+            i += 1;
+            while i < chars.len() && chars[i].1.is_ascii_digit() {
+                i += 1;
+            }
+
+            let is_float = chars.get(i).map(|&(_, c)| c) == Some('.')
+                && chars.get(i + 1).is_some_and(|&(_, c)| c.is_ascii_digit());
+
+            if is_float {
+                i += 1;
+                while i < chars.len() && chars[i].1.is_ascii_digit() {
+                    i += 1;
+                }
+                Kind::TOKEN_FLOAT
+            } else {
+                Kind::TOKEN_INTEGER
+            }
+        } else if is_identifier_start(ch) {
+            let word_start = i;
+            i += 1;
+            while i < chars.len() && is_identifier_continue(chars[i].1) {
+                i += 1;
+            }
+
+            let word: String = chars[word_start..i].iter().map(|&(_, c)| c).collect();
+            keyword(&word).unwrap_or(Kind::TOKEN_IDENTIFIER)
+        } else if let Some(kind) = punctuation(ch) {
+            i += 1;
+            kind
+        } else {
+            i += 1;
+
+            let end = chars.get(i).map_or(source.len(), |&(offset, _)| offset) as u32;
+            if let Some(recovery) = recover_error(ch, Span::new(start as u32, end)) {
+                recoveries.push(recovery);
+            }
+
+            Kind::TOKEN_ERROR
+        };
+
+        let end = chars.get(i).map_or(source.len(), |&(offset, _)| offset) as u32;
+        tokens.push(RawToken {
+            kind,
+            start: start as u32,
+            end,
+        });
+    }
+
+    (tokens, recoveries)
+}
+
+/// Whether `ch` can start an identifier: [`char::is_alphabetic`] or `_`.
+///
+/// Unlike [`is_identifier_continue`], a leading `-` is deliberately excluded
+/// so `1 - 2` still lexes `-` as [`Kind::TOKEN_MINUS`] instead of eating it
+/// into a one-character identifier; `-` only extends an identifier that has
+/// already started.
+fn is_identifier_start(ch: char) -> bool {
+    ch.is_alphabetic() || ch == '_'
+}
+
+/// Whether `ch` can continue an identifier that has already started:
+/// [`char::is_alphanumeric`], `_` or `-`.
+fn is_identifier_continue(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_' || ch == '-'
+}
+
+fn keyword(word: &str) -> Option<Kind> {
+    Some(match word {
+        "if" => Kind::TOKEN_LITERAL_IF,
+        "then" => Kind::TOKEN_LITERAL_THEN,
+        "else" => Kind::TOKEN_LITERAL_ELSE,
+        "and" => Kind::TOKEN_LITERAL_AND,
+        "or" => Kind::TOKEN_LITERAL_OR,
+        "not" => Kind::TOKEN_LITERAL_NOT,
+        _ => return None,
+    })
+}
+
+fn punctuation(ch: char) -> Option<Kind> {
+    Some(match ch {
+        '@' => Kind::TOKEN_AT,
+        '(' => Kind::TOKEN_LEFT_PARENTHESIS,
+        ')' => Kind::TOKEN_RIGHT_PARENTHESIS,
+        '[' => Kind::TOKEN_LEFT_BRACKET,
+        ']' => Kind::TOKEN_RIGHT_BRACKET,
+        '.' => Kind::TOKEN_PERIOD,
+        '{' => Kind::TOKEN_LEFT_CURLYBRACE,
+        '}' => Kind::TOKEN_RIGHT_CURLYBRACE,
+        '?' => Kind::TOKEN_QUESTIONMARK,
+        ';' => Kind::TOKEN_SEMICOLON,
+        '!' => Kind::TOKEN_EXCLAMATION,
+        '=' => Kind::TOKEN_EQUAL,
+        '<' => Kind::TOKEN_LESS,
+        '>' => Kind::TOKEN_MORE,
+        ',' => Kind::TOKEN_COMMA,
+        ':' => Kind::TOKEN_COLON,
+        '+' => Kind::TOKEN_PLUS,
+        '-' => Kind::TOKEN_MINUS,
+        '*' => Kind::TOKEN_ASTERISK,
+        '/' => Kind::TOKEN_SLASH,
+        '|' => Kind::TOKEN_PIPE,
+        _ => return None,
+    })
+}
+
+/// What to do after lexing a character that does not match any token rule.
+pub struct ErrorRecovery {
+    /// The suggestion to attach to the [`Kind::TOKEN_ERROR`] span, proposing
+    /// the ASCII character the user most likely meant.
+    pub suggestion: Suggestion,
+
+    /// The human-readable "Unicode character '…' (NAME) looks like '…'"
+    /// explanation backing `suggestion`, for diagnostics that want prose
+    /// rather than just a replacement.
+    pub message: String,
+}
+
+/// Tries to recover a character that would otherwise be lexed as
+/// [`Kind::TOKEN_ERROR`] by checking it against the
+/// [`unicode_confusable`] table.
+///
+/// The span is still classified as `TOKEN_ERROR` for the tree's integrity;
+/// only the suggestion and message attached to it change. Returns `None`
+/// when `ch` is not a known confusable, in which case the caller should
+/// fall back to a bare, unsuggested `TOKEN_ERROR`.
+pub fn recover_error(ch: char, span: Span) -> Option<ErrorRecovery> {
+    let confusable = unicode_confusable::lookup(ch)?;
+
+    Some(ErrorRecovery {
+        suggestion: Suggestion::new(
+            span,
+            confusable.intended.to_string(),
+            Applicability::MachineApplicable,
+        ),
+        message: confusable.message(ch),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn composite_punctuation_is_joint() {
+        assert_eq!(tokenize("==>"), vec![
+            (Kind::TOKEN_EQUAL, Spacing::Joint),
+            (Kind::TOKEN_EQUAL, Spacing::Joint),
+            (Kind::TOKEN_MORE, Spacing::Alone),
+        ]);
+    }
+
+    #[test]
+    fn whitespace_between_punctuation_forces_alone() {
+        assert_eq!(tokenize("= ="), vec![
+            (Kind::TOKEN_EQUAL, Spacing::Alone),
+            (Kind::TOKEN_EQUAL, Spacing::Alone),
+        ]);
+    }
+
+    #[test]
+    fn comment_between_punctuation_forces_alone() {
+        assert_eq!(tokenize("=#comment\n="), vec![
+            (Kind::TOKEN_EQUAL, Spacing::Alone),
+            (Kind::TOKEN_EQUAL, Spacing::Alone),
+        ]);
+    }
+
+    #[test]
+    fn interpolation_start_is_atomic() {
+        assert_eq!(tokenize(r"\("), vec![(Kind::TOKEN_INTERPOLATION_START, Spacing::Alone)]);
+    }
+
+    #[test]
+    fn unrecognized_character_is_token_error() {
+        assert_eq!(tokenize("`"), vec![(Kind::TOKEN_ERROR, Spacing::Alone)]);
+    }
+
+    #[test]
+    fn identifier_is_a_single_token() {
+        assert_eq!(tokenize("foo"), vec![(Kind::TOKEN_IDENTIFIER, Spacing::Alone)]);
+    }
+
+    #[test]
+    fn identifier_may_contain_hyphens_and_digits() {
+        assert_eq!(tokenize("foo-bar2"), vec![(Kind::TOKEN_IDENTIFIER, Spacing::Alone)]);
+    }
+
+    #[test]
+    fn leading_hyphen_is_minus_not_an_identifier() {
+        assert_eq!(tokenize("-foo"), vec![
+            (Kind::TOKEN_MINUS, Spacing::Joint),
+            (Kind::TOKEN_IDENTIFIER, Spacing::Alone),
+        ]);
+    }
+
+    #[test]
+    fn keywords_are_recognized() {
+        assert_eq!(tokenize("if then else and or not"), vec![
+            (Kind::TOKEN_LITERAL_IF, Spacing::Alone),
+            (Kind::TOKEN_LITERAL_THEN, Spacing::Alone),
+            (Kind::TOKEN_LITERAL_ELSE, Spacing::Alone),
+            (Kind::TOKEN_LITERAL_AND, Spacing::Alone),
+            (Kind::TOKEN_LITERAL_OR, Spacing::Alone),
+            (Kind::TOKEN_LITERAL_NOT, Spacing::Alone),
+        ]);
+    }
+
+    #[test]
+    fn integer_and_float_literals() {
+        assert_eq!(tokenize("42"), vec![(Kind::TOKEN_INTEGER, Spacing::Alone)]);
+        assert_eq!(tokenize("3.14"), vec![(Kind::TOKEN_FLOAT, Spacing::Alone)]);
+    }
+
+    #[test]
+    fn period_without_a_following_digit_is_not_part_of_a_float() {
+        assert_eq!(tokenize("1."), vec![
+            (Kind::TOKEN_INTEGER, Spacing::Joint),
+            (Kind::TOKEN_PERIOD, Spacing::Alone),
+        ]);
+    }
+
+    #[test]
+    fn confusable_character_is_still_token_error_but_gets_a_suggestion() {
+        let (tokens, recoveries) = tokenize_with_suggestions("\u{ff08}");
+
+        assert_eq!(tokens, vec![(Kind::TOKEN_ERROR, Spacing::Alone)]);
+        assert_eq!(recoveries.len(), 1);
+        assert_eq!(recoveries[0].suggestion.replacement, "(");
+        assert_eq!(recoveries[0].suggestion.applicability, Applicability::MachineApplicable);
+        assert_eq!(
+            recoveries[0].message,
+            "Unicode character '\u{ff08}' (FULLWIDTH LEFT PARENTHESIS) looks like '('"
+        );
+    }
+
+    #[test]
+    fn non_confusable_unrecognized_character_gets_no_suggestion() {
+        let (tokens, recoveries) = tokenize_with_suggestions("`");
+
+        assert_eq!(tokens, vec![(Kind::TOKEN_ERROR, Spacing::Alone)]);
+        assert!(recoveries.is_empty());
+    }
+
+    #[test]
+    fn recover_error_matches_by_exact_scalar_value() {
+        assert!(recover_error('\u{ff08}', Span::empty(0u32)).is_some());
+        assert!(recover_error('(', Span::empty(0u32)).is_none());
+        assert!(recover_error('a', Span::empty(0u32)).is_none());
+    }
+}
@@ -0,0 +1,265 @@
+use cab_text::{
+    MultiSpan,
+    Span,
+};
+
+use crate::{
+    Kind,
+    Spacing,
+};
+
+/// Tokens the skip-to-synchronization strategy treats as safe places to
+/// resume parsing after abandoning a broken construct.
+const SYNCHRONIZATION_TOKENS: &[Kind] = &[
+    Kind::TOKEN_SEMICOLON,
+    Kind::TOKEN_RIGHT_CURLYBRACE,
+    Kind::TOKEN_RIGHT_PARENTHESIS,
+    Kind::TOKEN_RIGHT_BRACKET,
+];
+
+/// Which error-recovery strategies the parser may use once it hits an
+/// unexpected token.
+///
+/// An editor integration wants every strategy on, so a single typo doesn't
+/// take the whole tree down with it; a strict one-shot compile wants them
+/// all off, so a broken construct is reported as `NODE_ERROR` rather than
+/// silently patched up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Recovery {
+    /// On an unexpected token, skip forward to the next
+    /// [`SYNCHRONIZATION_TOKENS`] member instead of abandoning the
+    /// enclosing construct immediately.
+    pub skip_to_synchronization_token: bool,
+
+    /// On a missing closing delimiter, synthesize one and mark its span
+    /// with a label naming the inserted delimiter instead of erroring the
+    /// whole construct. No [`Suggestion`](cab_text::Suggestion) is attached.
+    pub insert_missing_delimiter: bool,
+
+    /// Accept `;` where `,` was expected inside a list or attribute set,
+    /// and vice versa, marking the span with a label rather than erroring.
+    /// No [`Suggestion`](cab_text::Suggestion) is attached.
+    pub treat_confused_separator: bool,
+}
+
+impl Recovery {
+    /// Every strategy enabled. Intended for editor integrations, which
+    /// would rather keep a usable tree than stop at the first error.
+    pub const LENIENT: Self = Self {
+        skip_to_synchronization_token: true,
+        insert_missing_delimiter: true,
+        treat_confused_separator: true,
+    };
+
+    /// Every strategy disabled. Intended for a one-shot compile, which
+    /// should abandon a broken construct into `NODE_ERROR` rather than
+    /// guess at the user's intent.
+    pub const STRICT: Self = Self {
+        skip_to_synchronization_token: false,
+        insert_missing_delimiter: false,
+        treat_confused_separator: false,
+    };
+}
+
+impl Default for Recovery {
+    fn default() -> Self {
+        Self::STRICT
+    }
+}
+
+/// What a call to [`Parser::recover`] ended up doing.
+pub enum Recovered {
+    /// Skipped forward to a synchronization token, which is still pending.
+    Synchronized(MultiSpan),
+
+    /// Accepted a confused separator or an inserted delimiter in place of
+    /// the expected token.
+    Substituted(MultiSpan),
+
+    /// No strategy applied; the caller must abandon the construct into
+    /// `NODE_ERROR`.
+    Abandoned,
+}
+
+/// Walks a token stream of `(Kind, Spacing)` pairs, reassembling composite
+/// operators out of runs of single-character punctuation emitted by the
+/// lexer.
+pub struct Parser {
+    tokens: Vec<(Kind, Spacing)>,
+    position: usize,
+    recovery: Recovery,
+}
+
+impl Parser {
+    pub fn new(tokens: Vec<(Kind, Spacing)>, recovery: Recovery) -> Self {
+        Self {
+            tokens,
+            position: 0,
+            recovery,
+        }
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<(Kind, Spacing)> {
+        self.tokens.get(self.position + offset).copied()
+    }
+
+    fn peek(&self) -> Option<Kind> {
+        self.peek_at(0).map(|(kind, _)| kind)
+    }
+
+    /// Tries to match and consume a run of tokens forming a composite
+    /// operator, such as `[TOKEN_EQUAL, TOKEN_EQUAL, TOKEN_MORE]` for `==>`.
+    ///
+    /// Every token but the last must be reported as
+    /// [`Spacing::Joint`](crate::Spacing::Joint) by the lexer, i.e. glued to
+    /// the one after it with no intervening whitespace or trivia. The last
+    /// token's spacing is irrelevant, as it is not joining anything here.
+    ///
+    /// Returns `false` and consumes nothing if the run does not match.
+    pub fn eat_composite(&mut self, kinds: &[Kind]) -> bool {
+        let Some((last, rest)) = kinds.split_last() else {
+            return false;
+        };
+
+        for (offset, &kind) in rest.iter().enumerate() {
+            match self.peek_at(offset) {
+                Some((actual, Spacing::Joint)) if actual == kind => {},
+                _ => return false,
+            }
+        }
+
+        match self.peek_at(rest.len()) {
+            Some((actual, _)) if actual == *last => {},
+            _ => return false,
+        }
+
+        self.position += kinds.len();
+        true
+    }
+
+    /// Recovers from an unexpected `found` at `at` where `expected` was
+    /// wanted, trying each enabled [`Recovery`] strategy in turn.
+    ///
+    /// `found` being [`is_argument`](Kind::is_argument) decides whether a
+    /// missing closing delimiter may be inserted, so the construct can still
+    /// absorb the next token as an operand. Otherwise the construct is given
+    /// up: skipped to a synchronization token when that strategy is enabled,
+    /// or [`Recovered::Abandoned`] into `NODE_ERROR` when it is not.
+    pub fn recover(&mut self, at: Span, found: Kind, expected: Kind) -> Recovered {
+        if self.recovery.treat_confused_separator
+            && matches!(
+                (expected, found),
+                (Kind::TOKEN_COMMA, Kind::TOKEN_SEMICOLON)
+                    | (Kind::TOKEN_SEMICOLON, Kind::TOKEN_COMMA)
+            )
+        {
+            self.position += 1;
+
+            return Recovered::Substituted(MultiSpan::primary(at).label(
+                at,
+                format!("treated {found} as {expected} since they separate the same kind of list"),
+            ));
+        }
+
+        if self.recovery.insert_missing_delimiter
+            && matches!(
+                expected,
+                Kind::TOKEN_RIGHT_PARENTHESIS
+                    | Kind::TOKEN_RIGHT_BRACKET
+                    | Kind::TOKEN_RIGHT_CURLYBRACE
+            )
+            && found.is_argument()
+        {
+            return Recovered::Substituted(
+                MultiSpan::primary(at)
+                    .label(at, format!("inserted a missing {expected} here")),
+            );
+        }
+
+        if self.recovery.skip_to_synchronization_token {
+            let start = at;
+
+            while let Some(kind) = self.peek() {
+                if SYNCHRONIZATION_TOKENS.contains(&kind) {
+                    break;
+                }
+
+                self.position += 1;
+            }
+
+            return Recovered::Synchronized(
+                MultiSpan::primary(start).label(start, "skipped while recovering from this error"),
+            );
+        }
+
+        Recovered::Abandoned
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer;
+
+    #[test]
+    fn eat_composite_matches_joint_run() {
+        let mut parser = Parser::new(lexer::tokenize("==>"), Recovery::default());
+
+        assert!(parser.eat_composite(&[Kind::TOKEN_EQUAL, Kind::TOKEN_EQUAL, Kind::TOKEN_MORE]));
+        assert_eq!(parser.peek(), None);
+    }
+
+    #[test]
+    fn eat_composite_rejects_alone_run() {
+        let mut parser = Parser::new(lexer::tokenize("= ="), Recovery::default());
+
+        assert!(!parser.eat_composite(&[Kind::TOKEN_EQUAL, Kind::TOKEN_EQUAL]));
+        assert_eq!(parser.peek(), Some(Kind::TOKEN_EQUAL));
+    }
+
+    #[test]
+    fn strict_recovery_abandons_on_unexpected_token() {
+        let mut parser = Parser::new(lexer::tokenize("1"), Recovery::STRICT);
+
+        assert!(matches!(
+            parser.recover(Span::empty(0u32), Kind::TOKEN_INTEGER, Kind::TOKEN_RIGHT_PARENTHESIS),
+            Recovered::Abandoned
+        ));
+    }
+
+    #[test]
+    fn lenient_recovery_inserts_missing_delimiter_before_an_operand() {
+        let mut parser = Parser::new(lexer::tokenize("1"), Recovery::LENIENT);
+
+        assert!(matches!(
+            parser.recover(Span::empty(0u32), Kind::TOKEN_INTEGER, Kind::TOKEN_RIGHT_PARENTHESIS),
+            Recovered::Substituted(_)
+        ));
+    }
+
+    #[test]
+    fn lenient_recovery_treats_confused_separator() {
+        let mut parser = Parser::new(lexer::tokenize(";"), Recovery::LENIENT);
+
+        assert!(matches!(
+            parser.recover(Span::empty(0u32), Kind::TOKEN_SEMICOLON, Kind::TOKEN_COMMA),
+            Recovered::Substituted(_)
+        ));
+        assert_eq!(parser.peek(), None);
+    }
+
+    #[test]
+    fn lenient_recovery_skips_to_synchronization_token() {
+        let mut parser = Parser::new(lexer::tokenize("@ @ )"), Recovery {
+            skip_to_synchronization_token: true,
+            insert_missing_delimiter: false,
+            treat_confused_separator: false,
+        });
+
+        assert!(matches!(
+            parser.recover(Span::empty(0u32), Kind::TOKEN_INTEGER, Kind::TOKEN_RIGHT_PARENTHESIS),
+            Recovered::Synchronized(_)
+        ));
+        assert_eq!(parser.peek(), Some(Kind::TOKEN_RIGHT_PARENTHESIS));
+    }
+}
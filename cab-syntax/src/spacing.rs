@@ -0,0 +1,17 @@
+/// Whether a punctuation token is glued to the token that follows it.
+///
+/// The lexer computes this purely from byte adjacency: trivia
+/// ([`TOKEN_COMMENT`](crate::Kind::TOKEN_COMMENT),
+/// [`TOKEN_WHITESPACE`](crate::Kind::TOKEN_WHITESPACE)) between two tokens
+/// always forces [`Alone`](Spacing::Alone), so a `Joint` run can be
+/// reassembled into a composite operator without re-touching the source
+/// text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Spacing {
+    /// No whitespace or trivia separates this token from the next
+    /// significant one.
+    Joint,
+
+    /// Whitespace, trivia, or nothing at all follows this token.
+    Alone,
+}
@@ -0,0 +1,62 @@
+/// A Unicode character that visually resembles a piece of ASCII punctuation
+/// without being it, paired with the character it is confusable with and a
+/// human-readable name suitable for diagnostics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Confusable {
+    pub intended: char,
+    pub name: &'static str,
+}
+
+impl Confusable {
+    /// Renders the "did you mean" message shown for this confusable, e.g.
+    /// `"Unicode character '（' (FULLWIDTH LEFT PARENTHESIS) looks like '('"`.
+    pub fn message(&self, found: char) -> String {
+        format!(
+            "Unicode character '{found}' ({name}) looks like '{intended}'",
+            name = self.name,
+            intended = self.intended,
+        )
+    }
+}
+
+const fn confusable(intended: char, name: &'static str) -> Confusable {
+    Confusable { intended, name }
+}
+
+/// Looks up a character against the table of Unicode confusables, borrowed
+/// from rustc's `unicode_chars` recovery table and restricted to the
+/// punctuation [`Kind`](crate::Kind) recognizes as a single character.
+///
+/// Matching is by exact scalar value, so this can never misfire on a
+/// legitimate identifier character that merely looks unusual.
+pub fn lookup(ch: char) -> Option<Confusable> {
+    Some(match ch {
+        '\u{ff01}' => confusable('!', "FULLWIDTH EXCLAMATION MARK"),
+        '\u{ff08}' => confusable('(', "FULLWIDTH LEFT PARENTHESIS"),
+        '\u{ff09}' => confusable(')', "FULLWIDTH RIGHT PARENTHESIS"),
+        '\u{ff0a}' => confusable('*', "FULLWIDTH ASTERISK"),
+        '\u{ff0b}' => confusable('+', "FULLWIDTH PLUS SIGN"),
+        '\u{ff0c}' => confusable(',', "FULLWIDTH COMMA"),
+        '\u{2010}' => confusable('-', "HYPHEN"),
+        '\u{2013}' => confusable('-', "EN DASH"),
+        '\u{2014}' => confusable('-', "EM DASH"),
+        '\u{2212}' => confusable('-', "MINUS SIGN"),
+        '\u{ff0d}' => confusable('-', "FULLWIDTH HYPHEN-MINUS"),
+        '\u{ff0e}' => confusable('.', "FULLWIDTH FULL STOP"),
+        '\u{ff0f}' => confusable('/', "FULLWIDTH SOLIDUS"),
+        '\u{ff1a}' => confusable(':', "FULLWIDTH COLON"),
+        '\u{037e}' => confusable(';', "GREEK QUESTION MARK"),
+        '\u{ff1b}' => confusable(';', "FULLWIDTH SEMICOLON"),
+        '\u{ff1c}' => confusable('<', "FULLWIDTH LESS-THAN SIGN"),
+        '\u{ff1d}' => confusable('=', "FULLWIDTH EQUALS SIGN"),
+        '\u{ff1e}' => confusable('>', "FULLWIDTH GREATER-THAN SIGN"),
+        '\u{ff1f}' => confusable('?', "FULLWIDTH QUESTION MARK"),
+        '\u{ff20}' => confusable('@', "FULLWIDTH COMMERCIAL AT"),
+        '\u{ff3b}' => confusable('[', "FULLWIDTH LEFT SQUARE BRACKET"),
+        '\u{ff3d}' => confusable(']', "FULLWIDTH RIGHT SQUARE BRACKET"),
+        '\u{ff5b}' => confusable('{', "FULLWIDTH LEFT CURLY BRACKET"),
+        '\u{ff5c}' => confusable('|', "FULLWIDTH VERTICAL LINE"),
+        '\u{ff5d}' => confusable('}', "FULLWIDTH RIGHT CURLY BRACKET"),
+        _ => return None,
+    })
+}
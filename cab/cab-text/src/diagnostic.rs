@@ -0,0 +1,164 @@
+use crate::{
+    IntoSpan,
+    Span,
+};
+
+/// A primary [`Span`] plus any number of secondary spans, each labeled with
+/// an explanation of how it relates to the primary one.
+///
+/// This lets a diagnostic point at several related locations at once, e.g.
+/// "this infix operator" together with "but this operand has the wrong
+/// shape", instead of being limited to a single contiguous range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiSpan {
+    primary: Span,
+    labels: Vec<(Span, String)>,
+}
+
+impl From<Span> for MultiSpan {
+    fn from(primary: Span) -> Self {
+        Self::primary(primary)
+    }
+}
+
+impl MultiSpan {
+    /// Creates a [`MultiSpan`] with the given primary span and no labels.
+    #[inline]
+    pub fn primary(primary: Span) -> Self {
+        Self {
+            primary,
+            labels: Vec::new(),
+        }
+    }
+
+    /// Attaches a labeled secondary span, returning `self` for chaining.
+    #[inline]
+    pub fn label(mut self, span: Span, label: impl Into<String>) -> Self {
+        self.labels.push((span, label.into()));
+        self
+    }
+
+    /// Attaches a labeled secondary span taken from anything that implements
+    /// [`IntoSpan`], such as a [`SyntaxNode`](cstree::syntax::SyntaxNode) or
+    /// [`SyntaxToken`](cstree::syntax::SyntaxToken).
+    #[inline]
+    pub fn label_node(self, node: &impl IntoSpan, label: impl Into<String>) -> Self {
+        self.label(node.span(), label)
+    }
+
+    /// The primary span of this diagnostic.
+    #[inline]
+    pub fn span(&self) -> Span {
+        self.primary
+    }
+
+    /// The secondary spans, each paired with its label.
+    #[inline]
+    pub fn labels(&self) -> impl Iterator<Item = (Span, &str)> {
+        self.labels.iter().map(|(span, label)| (*span, label.as_str()))
+    }
+
+    /// Iterates over the primary span followed by every secondary span.
+    #[inline]
+    pub fn spans(&self) -> impl Iterator<Item = Span> + '_ {
+        std::iter::once(self.primary).chain(self.labels.iter().map(|(span, _)| *span))
+    }
+}
+
+/// How confident a [`Suggestion`] is that its replacement is correct, taken
+/// straight from rustc's suggestion applicability so tooling can decide
+/// whether to auto-apply a fix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Applicability {
+    /// The suggestion is definitely what the user intended and can be
+    /// applied mechanically.
+    MachineApplicable,
+
+    /// The suggestion may be incorrect and should be reviewed before being
+    /// applied.
+    MaybeIncorrect,
+
+    /// The suggestion contains placeholders, like `/* field */`, that the
+    /// user must fill in themselves.
+    HasPlaceholders,
+
+    /// The applicability is not known.
+    Unspecified,
+}
+
+/// A proposed fix: replace the text at `span` with `replacement`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    pub span: Span,
+    pub replacement: String,
+    pub applicability: Applicability,
+}
+
+impl Suggestion {
+    #[inline]
+    pub fn new(
+        span: Span,
+        replacement: impl Into<String>,
+        applicability: Applicability,
+    ) -> Self {
+        Self {
+            span,
+            replacement: replacement.into(),
+            applicability,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn primary_has_no_labels() {
+        let span = Span::new(0u32, 1u32);
+        let multi_span = MultiSpan::primary(span);
+
+        assert_eq!(multi_span.span(), span);
+        assert_eq!(multi_span.labels().count(), 0);
+        assert_eq!(multi_span.spans().collect::<Vec<_>>(), vec![span]);
+    }
+
+    #[test]
+    fn label_is_kept_separate_from_the_primary_span() {
+        let primary = Span::new(0u32, 1u32);
+        let secondary = Span::new(4u32, 5u32);
+
+        let multi_span = MultiSpan::primary(primary).label(secondary, "but this operand has the wrong shape");
+
+        assert_eq!(multi_span.span(), primary);
+        assert_eq!(
+            multi_span.labels().collect::<Vec<_>>(),
+            vec![(secondary, "but this operand has the wrong shape")]
+        );
+        assert_eq!(multi_span.spans().collect::<Vec<_>>(), vec![primary, secondary]);
+    }
+
+    #[test]
+    fn labels_are_ranked_in_the_order_they_were_added() {
+        let primary = Span::new(0u32, 1u32);
+        let first = Span::new(2u32, 3u32);
+        let second = Span::new(4u32, 5u32);
+
+        let multi_span = MultiSpan::primary(primary).label(first, "first").label(second, "second");
+
+        assert_eq!(
+            multi_span.labels().map(|(_, label)| label).collect::<Vec<_>>(),
+            vec!["first", "second"]
+        );
+    }
+
+    #[test]
+    fn suggestion_carries_its_applicability() {
+        let span = Span::new(0u32, 1u32);
+        let suggestion = Suggestion::new(span, "(", Applicability::MachineApplicable);
+
+        assert_eq!(suggestion.span, span);
+        assert_eq!(suggestion.replacement, "(");
+        assert_eq!(suggestion.applicability, Applicability::MachineApplicable);
+    }
+}
@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+
+use crate::{
+    Size,
+    Span,
+};
+
+/// A zero-based line and column position.
+///
+/// The unit `column` is counted in depends on which [`LineIndex`] method
+/// produced it: raw UTF-8 bytes, Unicode scalar values, or UTF-16 code
+/// units (what LSP wants).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LineColumn {
+    pub line: u32,
+    pub column: u32,
+}
+
+/// Which unit a [`LineColumn`]'s column is counted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnEncoding {
+    /// Raw UTF-8 bytes, matching [`Span`]'s own unit.
+    Utf8,
+
+    /// UTF-16 code units, as required by the Language Server Protocol.
+    Utf16,
+
+    /// Unicode scalar values, i.e. `char` count.
+    Scalar,
+}
+
+/// A single non-ASCII character on a line, recorded so columns can be reported in
+/// any [`ColumnEncoding`] without re-scanning the source text.
+#[derive(Debug, Clone, Copy)]
+struct WideChar {
+    /// Byte offset of the character, relative to the start of its line.
+    start: u32,
+
+    /// Its length in UTF-8 bytes. Always 2, 3 or 4, since this only ever
+    /// holds non-ASCII characters.
+    utf8_len: u32,
+}
+
+impl WideChar {
+    fn utf16_len(self) -> u32 {
+        if self.utf8_len == 4 { 2 } else { 1 }
+    }
+}
+
+/// A source-map built once from source text, resolving [`Span`] byte offsets
+/// to human-facing line/column positions and back, mirroring the source-map
+/// layer rust-analyzer and rustc maintain.
+#[derive(Debug, Clone)]
+pub struct LineIndex {
+    /// Byte offset of the start of every line. Line 0 always starts at
+    /// offset 0, even for an empty file.
+    newlines: Vec<Size>,
+
+    /// Non-ASCII characters, keyed by zero-based line number.
+    wide_chars: HashMap<u32, Vec<WideChar>>,
+
+    /// Total length of the indexed source, for offsets at EOF.
+    len: Size,
+}
+
+impl LineIndex {
+    /// Scans `text` for `\n` once, building the line-start table and the
+    /// per-line non-ASCII runs.
+    pub fn new(text: &str) -> Self {
+        let mut newlines = vec![Size::from(0u32)];
+        let mut wide_chars: HashMap<u32, Vec<WideChar>> = HashMap::new();
+
+        let mut line = 0u32;
+        let mut line_start = 0u32;
+
+        for (offset, ch) in text.char_indices() {
+            let offset = offset as u32;
+
+            if ch == '\n' {
+                newlines.push(Size::from(offset + 1));
+                line += 1;
+                line_start = offset + 1;
+                continue;
+            }
+
+            if !ch.is_ascii() {
+                wide_chars.entry(line).or_default().push(WideChar {
+                    start: offset - line_start,
+                    utf8_len: ch.len_utf8() as u32,
+                });
+            }
+        }
+
+        Self {
+            newlines,
+            wide_chars,
+            len: Size::from(text.len() as u32),
+        }
+    }
+
+    /// Resolves a byte offset to a zero-based `(line, column)` position,
+    /// with `column` counted in UTF-8 bytes from the line start.
+    ///
+    /// An offset exactly at a `\n` resolves to the end of the line it
+    /// terminates, not the start of the next one. An offset at EOF, or a
+    /// file with no trailing newline, both resolve the same way as any
+    /// other offset: relative to the last line start at or before it.
+    pub fn offset_to_position(&self, offset: Size) -> LineColumn {
+        let line = self.newlines.partition_point(|&start| start <= offset) - 1;
+        let line_start = self.newlines[line];
+
+        LineColumn {
+            line: line as u32,
+            column: *(offset - line_start),
+        }
+    }
+
+    /// The inverse of [`offset_to_position`](Self::offset_to_position):
+    /// resolves a UTF-8-byte `(line, column)` position back to a byte
+    /// offset. Out-of-range lines clamp to the end of the source.
+    pub fn position_to_offset(&self, position: LineColumn) -> Size {
+        let Some(&line_start) = self.newlines.get(position.line as usize) else {
+            return self.len;
+        };
+
+        line_start + Size::from(position.column)
+    }
+
+    /// Like [`offset_to_position`](Self::offset_to_position), but with the
+    /// column re-expressed in `encoding` instead of raw UTF-8 bytes.
+    pub fn offset_to_position_in(&self, offset: Size, encoding: ColumnEncoding) -> LineColumn {
+        let position = self.offset_to_position(offset);
+
+        let ColumnEncoding::Utf8 = encoding else {
+            return self.recode_column(position, encoding);
+        };
+
+        position
+    }
+
+    fn recode_column(&self, position: LineColumn, encoding: ColumnEncoding) -> LineColumn {
+        let Some(wide_chars) = self.wide_chars.get(&position.line) else {
+            return position;
+        };
+
+        let mut column = position.column;
+        for wide_char in wide_chars {
+            if wide_char.start >= position.column {
+                break;
+            }
+
+            column -= match encoding {
+                ColumnEncoding::Utf8 => 0,
+                ColumnEncoding::Utf16 => wide_char.utf8_len - wide_char.utf16_len(),
+                ColumnEncoding::Scalar => wide_char.utf8_len - 1,
+            };
+        }
+
+        LineColumn { line: position.line, column }
+    }
+
+    /// Resolves both ends of a [`Span`] to their UTF-8 `(line, column)`
+    /// positions.
+    pub fn span_to_positions(&self, span: Span) -> (LineColumn, LineColumn) {
+        (
+            self.offset_to_position(span.start),
+            self.offset_to_position(span.end),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset_at_newline_resolves_to_the_line_it_terminates() {
+        let index = LineIndex::new("ab\ncd");
+
+        assert_eq!(index.offset_to_position(Size::from(2u32)), LineColumn {
+            line: 0,
+            column: 2,
+        });
+        assert_eq!(index.offset_to_position(Size::from(3u32)), LineColumn {
+            line: 1,
+            column: 0,
+        });
+    }
+
+    #[test]
+    fn offset_at_eof_resolves_relative_to_the_last_line() {
+        let index = LineIndex::new("ab\ncd");
+
+        assert_eq!(index.offset_to_position(Size::from(5u32)), LineColumn {
+            line: 1,
+            column: 2,
+        });
+    }
+
+    #[test]
+    fn file_with_no_trailing_newline_has_a_single_line() {
+        let index = LineIndex::new("abc");
+
+        assert_eq!(index.offset_to_position(Size::from(3u32)), LineColumn {
+            line: 0,
+            column: 3,
+        });
+    }
+
+    #[test]
+    fn position_to_offset_is_the_inverse_of_offset_to_position() {
+        let index = LineIndex::new("ab\ncd\nef");
+
+        for offset in 0..=8u32 {
+            let position = index.offset_to_position(Size::from(offset));
+
+            assert_eq!(index.position_to_offset(position), Size::from(offset));
+        }
+    }
+
+    #[test]
+    fn scalar_column_counts_chars_not_bytes() {
+        // "é" is 2 UTF-8 bytes, 1 scalar value, 1 UTF-16 unit.
+        let index = LineIndex::new("é=");
+
+        let utf8 = index.offset_to_position(Size::from(2u32));
+        assert_eq!(utf8, LineColumn { line: 0, column: 2 });
+
+        let scalar = index.offset_to_position_in(Size::from(2u32), ColumnEncoding::Scalar);
+        assert_eq!(scalar, LineColumn { line: 0, column: 1 });
+
+        let utf16 = index.offset_to_position_in(Size::from(2u32), ColumnEncoding::Utf16);
+        assert_eq!(utf16, LineColumn { line: 0, column: 1 });
+    }
+
+    #[test]
+    fn utf16_column_counts_surrogate_pairs_as_two_units() {
+        // "𝄞" (U+1D11E) is 4 UTF-8 bytes, 1 scalar value, but 2 UTF-16 units.
+        let index = LineIndex::new("𝄞=");
+
+        let scalar = index.offset_to_position_in(Size::from(4u32), ColumnEncoding::Scalar);
+        assert_eq!(scalar, LineColumn { line: 0, column: 1 });
+
+        let utf16 = index.offset_to_position_in(Size::from(4u32), ColumnEncoding::Utf16);
+        assert_eq!(utf16, LineColumn { line: 0, column: 2 });
+    }
+}